@@ -0,0 +1,49 @@
+//! Extracting `.hrx` archives onto the filesystem.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{Archive, Error};
+
+impl Archive {
+    /// Extract every entry onto the filesystem under `dir`.
+    ///
+    /// Entry names are `/`-separated paths relative to `dir`;
+    /// intermediate directories are created as needed. A name ending in
+    /// `/` is materialized as an (empty) directory rather than a file;
+    /// any other entry with an empty body becomes an empty file.
+    ///
+    /// Returns [`Error::UnsafePath`] naming an entry whose name is
+    /// absolute or contains a `..` component that would let it escape
+    /// `dir`.
+    pub fn extract(&self, dir: &Path) -> Result<(), Error> {
+        for (name, entry) in self.files.iter() {
+            let path = safe_path(dir, name)?;
+            if name.ends_with('/') {
+                fs::create_dir_all(&path).map_err(Error::Io)?;
+            } else {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent).map_err(Error::Io)?;
+                }
+                fs::write(&path, &entry.body).map_err(Error::Io)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Resolve `name` as a path under `dir`, rejecting anything that would
+/// let it escape `dir`.
+fn safe_path(dir: &Path, name: &str) -> Result<PathBuf, Error> {
+    if name.starts_with('/') {
+        return Err(Error::UnsafePath(name.to_string()));
+    }
+    let mut path = dir.to_path_buf();
+    for part in name.split('/') {
+        match part {
+            "" => continue,
+            ".." => return Err(Error::UnsafePath(name.to_string())),
+            part => path.push(part),
+        }
+    }
+    Ok(path)
+}
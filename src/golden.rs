@@ -0,0 +1,106 @@
+//! Using `.hrx` archives as directory-based golden test fixtures, in the
+//! style of rust-analyzer's `dir_tests`.
+//!
+//! A single archive holds both the stimulus and the expected result for
+//! any number of cases, paired up by naming convention: either a
+//! `name.in` entry alongside a `name.out` entry, or a top-level `name`
+//! entry alongside an `expected/name` entry.
+use crate::Archive;
+
+/// One input/expected pair extracted from a fixture archive.
+#[derive(Debug, Clone)]
+pub struct Case {
+    /// The name shared by the input and expected entries, with any
+    /// `.in` suffix or `expected/` prefix stripped.
+    pub name: String,
+    /// The stimulus to feed to the transform under test.
+    pub input: String,
+    /// The output the transform is expected to produce.
+    pub expected: String,
+    /// The entry name the expected output was read from, so a bless
+    /// pass knows where to write an updated result back.
+    #[cfg_attr(not(feature = "write"), allow(dead_code))]
+    expected_name: String,
+}
+
+impl Archive {
+    /// Split this archive's entries into golden-test cases, pairing each
+    /// `name.in` entry with its `name.out` sibling, or each other entry
+    /// with its `expected/name` sibling.
+    ///
+    /// Entries that don't fit either convention (including the `expected/`
+    /// entries themselves) are ignored.
+    pub fn cases(&self) -> Vec<Case> {
+        let mut cases = Vec::new();
+        for (name, input) in self.entries() {
+            let (case_name, expected_name) = match name.strip_suffix(".in") {
+                Some(stem) => (stem.to_string(), format!("{stem}.out")),
+                None if !name.starts_with("expected/") => {
+                    (name.to_string(), format!("expected/{name}"))
+                }
+                None => continue,
+            };
+            if let Some(expected) = self.get(&expected_name) {
+                cases.push(Case {
+                    name: case_name,
+                    input: input.to_string(),
+                    expected: expected.to_string(),
+                    expected_name,
+                });
+            }
+        }
+        cases
+    }
+
+    /// Run `transform` over every case from [`Archive::cases`] and assert
+    /// that its output matches the stored expected entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics naming every mismatching case, along with its expected and
+    /// actual output in full, once all cases have been run.
+    pub fn run_golden_tests(&self, transform: impl Fn(&str) -> String) {
+        let failures: Vec<String> = self
+            .cases()
+            .into_iter()
+            .filter_map(|case| {
+                let actual = transform(&case.input);
+                (actual != case.expected).then(|| {
+                    format!(
+                        "case {:?}:\n--- expected ---\n{}\n--- actual ---\n{}",
+                        case.name, case.expected, actual
+                    )
+                })
+            })
+            .collect();
+        if !failures.is_empty() {
+            panic!(
+                "{} golden test case(s) failed:\n\n{}",
+                failures.len(),
+                failures.join("\n")
+            );
+        }
+    }
+
+    /// Like [`Archive::run_golden_tests`], but instead of panicking on a
+    /// mismatch, "blesses" it by rewriting the case's expected entry in
+    /// place with the actual output.
+    ///
+    /// The archive itself is updated in memory; callers that want the
+    /// blessed result on disk still need to [`serialize`](Archive::serialize)
+    /// and write it back out.
+    #[cfg(feature = "write")]
+    pub fn bless_golden_tests(&mut self, transform: impl Fn(&str) -> String) {
+        let updates: Vec<(String, String)> = self
+            .cases()
+            .into_iter()
+            .filter_map(|case| {
+                let actual = transform(&case.input);
+                (actual != case.expected).then_some((case.expected_name, actual))
+            })
+            .collect();
+        for (expected_name, actual) in updates {
+            self.insert(expected_name, actual);
+        }
+    }
+}
@@ -1,9 +1,20 @@
-//! Implement simple reading of Human Readable Archive (.hrx) data.
+//! Implement simple reading and writing of Human Readable Archive (.hrx) data.
 //!
 //! The Human Readable Achive format specification lives at
 //! [https://github.com/google/hrx](https://github.com/google/hrx).
 //!
-//! This crate only supports _reading_ `.hrx` data.
+//! Writing archives back out to `.hrx` format is available behind the
+//! `write` feature.
+//!
+//! For large archives, [`Archive::from_reader`] yields entries lazily
+//! from any [`std::io::Read`] instead of requiring the whole archive to
+//! be loaded up front.
+//!
+//! [`Archive::extract`] writes an archive back out as a directory tree.
+//!
+//! [`Archive::cases`] and [`Archive::run_golden_tests`] let an archive
+//! double as a directory-based snapshot-test fixture, pairing up input
+//! and expected-output entries.
 //!
 //! # Example
 //!
@@ -23,15 +34,36 @@
 //! # Ok(())
 //! # }
 //! ```
-use std::collections::BTreeMap;
 use std::fmt::{self, Display};
 use std::fs::read_to_string;
 use std::path::{Path, PathBuf};
 
+use ordermap::OrderMap;
+
+mod extract;
+mod golden;
+mod ordermap;
+mod reader;
+#[cfg(feature = "write")]
+mod write;
+
+pub use golden::Case;
+pub use reader::Reader;
+
+/// A file entry together with the comment, if any, that preceded it.
+#[derive(Debug, Clone, Default)]
+struct Entry {
+    body: String,
+    comment: Option<String>,
+}
+
 /// Parsed Human Readable Archive data.
+///
+/// Entries are kept in the order they appear in the source archive.
 #[derive(Debug)]
 pub struct Archive {
-    files: BTreeMap<String, String>,
+    files: OrderMap<Entry>,
+    comment: Option<String>,
 }
 
 impl Archive {
@@ -43,40 +75,85 @@ impl Archive {
 
     /// Parse hrx data from an in-memory buffer.
     pub fn parse(data: &str) -> Result<Archive, Error> {
-        let mut files = BTreeMap::new();
+        let mut files = OrderMap::new();
+        let mut comment = None;
+        let mut pending_comment = None;
         let boundary = format!("\n{}", find_boundary(data).ok_or(Error::NoBoundary)?);
-        for item in data[boundary.len() - 1..].split(&boundary) {
-            if item.is_empty() || item.starts_with('\n') {
-                // item is a comment, ignore it.
-            } else if let Some(item) = item.strip_prefix(' ') {
-                if let Some(nl) = item.find('\n') {
-                    let name = &item[..nl];
-                    let body = &item[1 + nl..];
-                    files.insert(name.into(), body.into());
-                } else {
-                    // Directory / empty file
-                    files.insert(item.into(), String::new());
+        let mut offset = boundary.len() - 1;
+        for item in data[offset..].split(&boundary) {
+            if item.is_empty() {
+                // No comment text here, e.g. a trailing boundary.
+            } else if let Some(text) = item.strip_prefix('\n') {
+                // Consecutive comment blocks (no entry between them) are
+                // concatenated rather than letting the later one clobber
+                // the earlier, so a multi-paragraph comment survives.
+                match &mut pending_comment {
+                    Some(existing) => {
+                        existing.push('\n');
+                        existing.push_str(text);
+                    }
+                    None => pending_comment = Some(text.to_string()),
                 }
+            } else if let Some(rest) = item.strip_prefix(' ') {
+                let (name, body) = match rest.find('\n') {
+                    Some(nl) => (&rest[..nl], &rest[1 + nl..]),
+                    // Directory / empty file
+                    None => (rest, ""),
+                };
+                let entry_comment = if files.is_empty() {
+                    // A comment before the first entry is the archive's
+                    // leading comment, not an entry's preceding comment.
+                    comment = pending_comment.take();
+                    None
+                } else {
+                    pending_comment.take()
+                };
+                files.insert(
+                    name.into(),
+                    Entry {
+                        body: body.into(),
+                        comment: entry_comment,
+                    },
+                );
             } else {
-                return Err(Error::InvalidItem(item.into()));
+                return Err(Error::InvalidItem {
+                    at: Position::at(data, offset),
+                    item: item.into(),
+                });
             }
+            offset += item.len() + boundary.len();
         }
-        Ok(Archive { files })
+        Ok(Archive { files, comment })
     }
 
-    /// Get a vec of the file names in the archive.
+    /// Get a vec of the file names in the archive, in archive order.
     pub fn names(&self) -> Vec<&str> {
-        self.files.keys().map(|s| s.as_ref()).collect()
+        self.files.keys().collect()
     }
 
     /// Get the contents of a file in the archive.
     pub fn get(&self, name: &str) -> Option<&str> {
-        self.files.get(name).map(|s| s.as_ref())
+        self.files.get(name).map(|e| e.body.as_ref())
     }
 
-    /// Iterate over (name, content) pairs for the files in the archive.
+    /// Iterate over (name, content) pairs for the files in the archive,
+    /// in archive order.
     pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
-        self.files.iter().map(|(k, v)| (k.as_ref(), v.as_ref()))
+        self.files.iter().map(|(k, v)| (k, v.body.as_ref()))
+    }
+
+    /// Iterate over (name, content, comment) triples, where `comment` is
+    /// the comment, if any, that preceded this entry in the source.
+    pub fn entries_with_comments(&self) -> impl Iterator<Item = (&str, &str, Option<&str>)> {
+        self.files
+            .iter()
+            .map(|(k, v)| (k, v.body.as_ref(), v.comment.as_deref()))
+    }
+
+    /// Get the archive-level comment, i.e. text preceding the first
+    /// entry in the source, if any.
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
     }
 }
 
@@ -121,8 +198,29 @@ impl Display for FileError {
 pub enum Error {
     /// No archive bound found
     NoBoundary,
-    /// Invalid item in archive
-    InvalidItem(String),
+    /// An item did not start with a space (a named entry) or a newline
+    /// (a comment) right after the boundary.
+    InvalidItem {
+        /// Where in the original data the bad item starts.
+        at: Position,
+        /// The offending item itself.
+        item: String,
+    },
+    /// No boundary sequence is safe for some entries.
+    ///
+    /// Every body in an archive must avoid containing a newline followed
+    /// by the chosen boundary sequence, or the archive would be
+    /// ambiguous to parse back. This error lists the names of the
+    /// entries that contain the boundary that was tried.
+    #[cfg(feature = "write")]
+    UnsafeBoundary(Vec<String>),
+    /// I/O error while streaming archive data from a [`Reader`], or
+    /// while extracting an archive to the filesystem.
+    Io(std::io::Error),
+    /// An entry name passed to [`Archive::extract`] is absolute or
+    /// contains a `..` component, and so would escape the destination
+    /// directory.
+    UnsafePath(String),
 }
 
 impl std::error::Error for Error {}
@@ -133,9 +231,62 @@ impl Display for Error {
             Error::NoBoundary => {
                 write!(out, "No archive boundary found")
             }
-            Error::InvalidItem(item) => {
-                write!(out, "Invalid item: {:?}", item)
+            Error::InvalidItem { at, .. } => {
+                write!(out, "Parse failed at {at}: expected \" \" or \"\\n\"")
+            }
+            #[cfg(feature = "write")]
+            Error::UnsafeBoundary(names) => {
+                write!(out, "No safe boundary for entries: {}", names.join(", "))
             }
+            Error::Io(e) => {
+                write!(out, "I/O error: {e}")
+            }
+            Error::UnsafePath(name) => {
+                write!(out, "Unsafe entry path, would escape destination directory: {name}")
+            }
+        }
+    }
+}
+
+/// A position within parsed `.hrx` data, used to locate parse errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column number.
+    pub column: usize,
+    /// 0-based byte offset.
+    pub offset: usize,
+}
+
+impl Position {
+    /// Compute the line and column of a byte offset into `data`.
+    fn at(data: &str, offset: usize) -> Position {
+        let mut pos = Position {
+            line: 1,
+            column: 1,
+            offset: 0,
+        };
+        pos.advance(&data[..offset]);
+        pos
+    }
+
+    /// Advance this position past `text`, as if it had just been consumed.
+    pub(crate) fn advance(&mut self, text: &str) {
+        for c in text.chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+            self.offset += c.len_utf8();
         }
     }
 }
+
+impl Display for Position {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        write!(out, "{}:{} [offset {}]", self.line, self.column, self.offset)
+    }
+}
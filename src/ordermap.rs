@@ -0,0 +1,67 @@
+//! A minimal insertion-order-preserving map, just enough for [`Archive`](crate::Archive).
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub(crate) struct OrderMap<V> {
+    order: Vec<String>,
+    index: HashMap<String, usize>,
+    values: Vec<V>,
+}
+
+impl<V> Default for OrderMap<V> {
+    fn default() -> Self {
+        OrderMap {
+            order: Vec::new(),
+            index: HashMap::new(),
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<V> OrderMap<V> {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Insert a value, keeping its original position if the key already
+    /// existed. Returns the replaced value, if any.
+    pub(crate) fn insert(&mut self, key: String, value: V) -> Option<V> {
+        if let Some(&i) = self.index.get(&key) {
+            Some(std::mem::replace(&mut self.values[i], value))
+        } else {
+            self.index.insert(key.clone(), self.order.len());
+            self.order.push(key);
+            self.values.push(value);
+            None
+        }
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<&V> {
+        self.index.get(key).map(|&i| &self.values[i])
+    }
+
+    /// Remove a value by key, shifting later entries down to keep order.
+    #[cfg_attr(not(feature = "write"), allow(dead_code))]
+    pub(crate) fn remove(&mut self, key: &str) -> Option<V> {
+        let i = self.index.remove(key)?;
+        self.order.remove(i);
+        for idx in self.index.values_mut() {
+            if *idx > i {
+                *idx -= 1;
+            }
+        }
+        Some(self.values.remove(i))
+    }
+
+    pub(crate) fn keys(&self) -> impl Iterator<Item = &str> {
+        self.order.iter().map(|s| s.as_str())
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &V)> {
+        self.order.iter().map(|k| k.as_str()).zip(self.values.iter())
+    }
+}
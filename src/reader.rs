@@ -0,0 +1,139 @@
+//! A streaming reader over `.hrx` data, yielding entries one at a time
+//! without requiring the whole archive to be held in memory.
+use std::io::{BufRead, BufReader, Read};
+
+use crate::{find_boundary, Archive, Error, Position};
+
+impl Archive {
+    /// Create a streaming reader over `.hrx` data from any [`Read`].
+    ///
+    /// Unlike [`Archive::parse`], the underlying reader is only
+    /// consumed as entries are pulled from the returned [`Reader`], so
+    /// large archives don't need to be resident in memory all at once.
+    /// The first read establishes the boundary length; the rest of the
+    /// archive is then scanned for it incrementally.
+    pub fn from_reader<R: Read>(r: R) -> Reader<R> {
+        Reader {
+            inner: BufReader::new(r),
+            boundary: None,
+            buf: String::new(),
+            pos: Position {
+                line: 1,
+                column: 1,
+                offset: 0,
+            },
+            done: false,
+        }
+    }
+}
+
+/// A lazy, pull-based reader over `.hrx` entries.
+///
+/// Created with [`Archive::from_reader`]; yields `(name, body)` pairs,
+/// skipping comments, in the same order they appear in the archive.
+pub struct Reader<R> {
+    inner: BufReader<R>,
+    boundary: Option<String>,
+    buf: String,
+    pos: Position,
+    done: bool,
+}
+
+impl<R: Read> Reader<R> {
+    /// Read one more line into `buf`. Returns `false` at EOF.
+    fn fill(&mut self) -> Result<bool, Error> {
+        let mut line = String::new();
+        let n = self.inner.read_line(&mut line).map_err(Error::Io)?;
+        self.buf.push_str(&line);
+        Ok(n > 0)
+    }
+
+    /// Read lines until the opening boundary token is fully buffered.
+    fn establish_boundary(&mut self) -> Result<(), Error> {
+        loop {
+            if let Some(token) = find_boundary(&self.buf) {
+                let token = token.to_string();
+                self.pos.advance(&self.buf[..token.len()]);
+                self.buf.drain(..token.len());
+                self.boundary = Some(format!("\n{token}"));
+                return Ok(());
+            }
+            if !self.fill()? {
+                return Err(Error::NoBoundary);
+            }
+        }
+    }
+
+    /// Pull the next raw item (the text between two boundaries), reading
+    /// more of the underlying stream as needed. Returns `None` once
+    /// there is nothing left at all.
+    fn next_item(&mut self) -> Result<Option<String>, Error> {
+        let boundary = self.boundary.clone().expect("boundary established");
+        loop {
+            if let Some(at) = self.buf.find(&boundary) {
+                let item = self.buf[..at].to_string();
+                self.pos.advance(&item);
+                self.pos.advance(&boundary);
+                self.buf.drain(..at + boundary.len());
+                return Ok(Some(item));
+            }
+            if !self.fill()? {
+                if self.buf.is_empty() {
+                    self.done = true;
+                    return Ok(None);
+                }
+                // The archive ended without a closing boundary; treat
+                // whatever is left as the final, unterminated item.
+                let item = std::mem::take(&mut self.buf);
+                self.pos.advance(&item);
+                self.done = true;
+                return Ok(Some(item));
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for Reader<R> {
+    type Item = Result<(String, String), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.boundary.is_none() && !self.done {
+            if let Err(e) = self.establish_boundary() {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+        loop {
+            let at_item_start = self.pos;
+            let item = match self.next_item() {
+                Ok(Some(item)) => item,
+                Ok(None) => return None,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            };
+            if item.is_empty() {
+                // A trailing boundary with nothing after it.
+            } else if item.starts_with('\n') {
+                // A comment; skip it.
+            } else if let Some(rest) = item.strip_prefix(' ') {
+                let (name, body) = match rest.find('\n') {
+                    Some(nl) => (rest[..nl].to_string(), rest[1 + nl..].to_string()),
+                    // Directory / empty file
+                    None => (rest.to_string(), String::new()),
+                };
+                return Some(Ok((name, body)));
+            } else {
+                self.done = true;
+                return Some(Err(Error::InvalidItem {
+                    at: at_item_start,
+                    item,
+                }));
+            }
+            if self.done {
+                return None;
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Writing and serializing `.hrx` archives.
+use std::io::{self, Write};
+
+use crate::{Archive, Entry, Error};
+
+impl Archive {
+    /// Create a new, empty archive.
+    pub fn new() -> Archive {
+        Archive {
+            files: Default::default(),
+            comment: None,
+        }
+    }
+
+    /// Insert or replace the body of an entry.
+    ///
+    /// Returns the previous body, if any.
+    pub fn insert(&mut self, name: impl Into<String>, body: impl Into<String>) -> Option<String> {
+        let name = name.into();
+        let comment = self.files.get(&name).and_then(|e| e.comment.clone());
+        self.files
+            .insert(
+                name,
+                Entry {
+                    body: body.into(),
+                    comment,
+                },
+            )
+            .map(|e| e.body)
+    }
+
+    /// Remove an entry, returning its body if it existed.
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        self.files.remove(name).map(|e| e.body)
+    }
+
+    /// Serialize this archive to `.hrx` format.
+    ///
+    /// The shortest boundary sequence (starting at a single `=`) that is
+    /// safe for every body (file content, archive comment, or entry
+    /// comment) is selected automatically. Returns
+    /// [`Error::UnsafeBoundary`] naming the offending entries if no
+    /// boundary of up to two `=` more than the length of the longest body
+    /// can be made safe.
+    pub fn serialize(&self) -> Result<String, Error> {
+        let max_len = self.bodies().map(|(_, body)| body.len()).max().unwrap_or(0) + 2;
+        let mut offenders = Vec::new();
+        for len in 1..=max_len {
+            match self.serialize_with_boundary_len(len) {
+                Ok(data) => return Ok(data),
+                Err(Error::UnsafeBoundary(names)) => offenders = names,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(Error::UnsafeBoundary(offenders))
+    }
+
+    /// Serialize this archive using a boundary of exactly `len` `=` signs.
+    ///
+    /// Returns [`Error::UnsafeBoundary`] naming the entries whose body
+    /// would make the archive ambiguous with this boundary length.
+    pub fn serialize_with_boundary_len(&self, len: usize) -> Result<String, Error> {
+        let boundary = format!("<{}>", "=".repeat(len));
+        let needle = format!("\n{boundary}");
+        // A body is unsafe both if it contains the boundary after some
+        // internal newline, and if it starts with the boundary right
+        // after its separator.
+        let bad: Vec<String> = self
+            .bodies()
+            .filter(|(_, body)| body.contains(&needle) || body.starts_with(&boundary))
+            .map(|(name, _)| name)
+            .collect();
+        if !bad.is_empty() {
+            return Err(Error::UnsafeBoundary(bad));
+        }
+        let mut out = String::new();
+        if let Some(comment) = &self.comment {
+            out.push_str(&boundary);
+            out.push_str(&format!("\n{comment}\n"));
+        }
+        for (name, entry) in self.files.iter() {
+            if let Some(comment) = &entry.comment {
+                out.push_str(&boundary);
+                out.push_str(&format!("\n{comment}\n"));
+            }
+            out.push_str(&boundary);
+            out.push_str(&format!(" {name}\n{}\n", entry.body));
+        }
+        out.push_str(&boundary);
+        out.push('\n');
+        Ok(out)
+    }
+
+    /// Write this archive to `.hrx` format.
+    ///
+    /// This is a convenience wrapper around [`Archive::serialize`].
+    pub fn write_to(&self, out: &mut impl Write) -> io::Result<()> {
+        out.write_all(self.serialize()?.as_bytes())
+    }
+
+    /// All the bodies (file contents and comments) that must be checked
+    /// for boundary safety, tagged with a name for error reporting.
+    fn bodies(&self) -> impl Iterator<Item = (String, &str)> {
+        let archive_comment = self
+            .comment
+            .as_deref()
+            .map(|c| ("<archive comment>".to_string(), c));
+        let entries = self.files.iter().flat_map(|(name, entry)| {
+            let comment = entry
+                .comment
+                .as_deref()
+                .map(|c| (format!("{name} (comment)"), c));
+            comment.into_iter().chain([(name.to_string(), entry.body.as_str())])
+        });
+        archive_comment.into_iter().chain(entries)
+    }
+}
+
+impl Default for Archive {
+    fn default() -> Self {
+        Archive::new()
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+    }
+}
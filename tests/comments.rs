@@ -0,0 +1,40 @@
+use hrx_get::Archive;
+
+static DATA: &str = "<===>\
+                     \nThis is the archive comment.\
+                     \n<===> one.txt\
+                     \nContent of one text file\
+                     \n<===>\
+                     \nThis is a comment\
+                     \n<===> subdir/file.txt\
+                     \nContents of a file in a subdir.\
+                     \n<===>\n";
+
+#[test]
+fn archive_comment() {
+    let archive = Archive::parse(DATA).unwrap();
+    assert_eq!(archive.comment(), Some("This is the archive comment."));
+}
+
+#[test]
+fn no_archive_comment() {
+    let archive = Archive::parse("<===> one.txt\nhello\n<===>\n").unwrap();
+    assert_eq!(archive.comment(), None);
+}
+
+#[test]
+fn entry_preceding_comment() {
+    let archive = Archive::parse(DATA).unwrap();
+    let entries: Vec<_> = archive.entries_with_comments().collect();
+    assert_eq!(
+        entries,
+        vec![
+            ("one.txt", "Content of one text file", None),
+            (
+                "subdir/file.txt",
+                "Contents of a file in a subdir.",
+                Some("This is a comment")
+            ),
+        ]
+    );
+}
@@ -0,0 +1,32 @@
+use hrx_get::{Archive, Error};
+
+#[test]
+fn no_boundary() {
+    assert_eq!(
+        Archive::parse("plain text, no boundary here")
+            .unwrap_err()
+            .to_string(),
+        "No archive boundary found"
+    );
+}
+
+#[test]
+fn invalid_item_position() {
+    let data = "<===> one.txt\nfirst\n<===>bad item without space or newline";
+    match Archive::parse(data) {
+        Err(Error::InvalidItem { at, item }) => {
+            assert_eq!(at.line, 3);
+            assert_eq!(at.column, 6);
+            assert_eq!(at.offset, data.find("bad item").unwrap());
+            assert!(item.starts_with("bad item"));
+        }
+        other => panic!("expected InvalidItem error, got {other:?}"),
+    }
+    assert_eq!(
+        Archive::parse(data).unwrap_err().to_string(),
+        format!(
+            "Parse failed at 3:6 [offset {}]: expected \" \" or \"\\n\"",
+            data.find("bad item").unwrap()
+        )
+    );
+}
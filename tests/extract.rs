@@ -0,0 +1,80 @@
+use hrx_get::{Archive, Error};
+
+static DATA: &str = "<===> hello.md\
+                     \n# Hello world\
+                     \n\
+                     \n<===> subdir/file.txt\
+                     \nContents of a file in a subdir.\
+                     \n\
+                     \n<===> subdir/empty.txt\
+                     \n<===> emptydir/\
+                     \n<===>\n";
+
+#[test]
+fn extract_tree() {
+    let dir = tempdir();
+    let archive = Archive::parse(DATA).unwrap();
+    archive.extract(dir.path()).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("hello.md")).unwrap(),
+        "# Hello world\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("subdir/file.txt")).unwrap(),
+        "Contents of a file in a subdir.\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("subdir/empty.txt")).unwrap(),
+        ""
+    );
+    assert!(dir.path().join("emptydir").is_dir());
+}
+
+#[test]
+fn extract_rejects_parent_traversal() {
+    let dir = tempdir();
+    let archive = Archive::parse("<===> ../escape.txt\nhi\n<===>\n").unwrap();
+    match archive.extract(dir.path()) {
+        Err(Error::UnsafePath(name)) => assert_eq!(name, "../escape.txt"),
+        other => panic!("expected UnsafePath error, got {other:?}"),
+    }
+}
+
+#[test]
+fn extract_rejects_absolute_path() {
+    let dir = tempdir();
+    let archive = Archive::parse("<===> /etc/passwd\nhi\n<===>\n").unwrap();
+    match archive.extract(dir.path()) {
+        Err(Error::UnsafePath(name)) => assert_eq!(name, "/etc/passwd"),
+        other => panic!("expected UnsafePath error, got {other:?}"),
+    }
+}
+
+/// A bare-bones scratch directory, cleaned up on drop, without pulling in
+/// a dev-dependency for it.
+struct TempDir(std::path::PathBuf);
+
+impl TempDir {
+    fn path(&self) -> &std::path::Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn tempdir() -> TempDir {
+    let dir = std::env::temp_dir().join(format!(
+        "hrx-get-extract-test-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    TempDir(dir)
+}
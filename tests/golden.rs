@@ -0,0 +1,52 @@
+use hrx_get::Archive;
+
+static DATA: &str = "<===> upper.in\
+                     \nhello\
+                     \n\
+                     \n<===> upper.out\
+                     \nHELLO\
+                     \n\
+                     \n<===> reverse\
+                     \nabc\
+                     \n\
+                     \n<===> expected/reverse\
+                     \ncba\
+                     \n\
+                     \n<===>\n";
+
+fn uppercase(input: &str) -> String {
+    input.to_uppercase()
+}
+
+#[test]
+fn finds_cases_by_both_conventions() {
+    let archive = Archive::parse(DATA).unwrap();
+    let mut cases = archive.cases();
+    cases.sort_by(|a, b| a.name.cmp(&b.name));
+    assert_eq!(cases.len(), 2);
+    assert_eq!(cases[0].name, "reverse");
+    assert_eq!(cases[0].input, "abc\n");
+    assert_eq!(cases[0].expected, "cba\n");
+    assert_eq!(cases[1].name, "upper");
+    assert_eq!(cases[1].input, "hello\n");
+    assert_eq!(cases[1].expected, "HELLO\n");
+}
+
+#[test]
+fn run_golden_tests_passes_on_matching_transform() {
+    let archive = Archive::parse(
+        "<===> upper.in\nhello\n<===> upper.out\nHELLO\n<===>\n",
+    )
+    .unwrap();
+    archive.run_golden_tests(uppercase);
+}
+
+#[test]
+#[should_panic(expected = "1 golden test case(s) failed")]
+fn run_golden_tests_panics_on_mismatch() {
+    let archive = Archive::parse(
+        "<===> upper.in\nhello\n<===> upper.out\nwrong\n<===>\n",
+    )
+    .unwrap();
+    archive.run_golden_tests(uppercase);
+}
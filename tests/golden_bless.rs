@@ -0,0 +1,13 @@
+#![cfg(feature = "write")]
+use hrx_get::Archive;
+
+#[test]
+fn bless_rewrites_expected_entry() {
+    let mut archive =
+        Archive::parse("<===> upper.in\nhello\n<===> upper.out\nwrong\n<===>\n").unwrap();
+    archive.bless_golden_tests(|s| s.to_uppercase());
+    assert_eq!(archive.get("upper.out"), Some("HELLO"));
+    // Blessing again is a no-op once the expected output matches.
+    archive.bless_golden_tests(|s| s.to_uppercase());
+    assert_eq!(archive.get("upper.out"), Some("HELLO"));
+}
@@ -0,0 +1,21 @@
+use hrx_get::Archive;
+
+static DATA: &str = "<===> z.txt\
+                     \nz\
+                     \n<===> a.txt\
+                     \na\
+                     \n<===> m.txt\
+                     \nm\n";
+
+#[test]
+fn names_preserve_archive_order() {
+    let archive = Archive::parse(DATA).unwrap();
+    assert_eq!(archive.names(), ["z.txt", "a.txt", "m.txt"]);
+}
+
+#[test]
+fn entries_preserve_archive_order() {
+    let archive = Archive::parse(DATA).unwrap();
+    let names: Vec<_> = archive.entries().map(|(name, _)| name).collect();
+    assert_eq!(names, ["z.txt", "a.txt", "m.txt"]);
+}
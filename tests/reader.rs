@@ -0,0 +1,46 @@
+use hrx_get::{Archive, Error};
+
+static DATA: &str = "<===> hello.md\
+                     \n# Hello world\
+                     \nThis is a simple markdown file.\
+                     \n\
+                     \n<===>\
+                     \nThis is just a comment.\
+                     \n<===> foo.txt\
+                     \nThis is something else.\n";
+
+#[test]
+fn reads_entries_in_order() {
+    let entries: Result<Vec<_>, Error> = Archive::from_reader(DATA.as_bytes()).collect();
+    assert_eq!(
+        entries.unwrap(),
+        vec![
+            (
+                "hello.md".to_string(),
+                "# Hello world\nThis is a simple markdown file.\n".to_string()
+            ),
+            ("foo.txt".to_string(), "This is something else.\n".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn matches_eager_parse() {
+    let eager = Archive::parse(DATA).unwrap();
+    let streamed: Vec<_> = Archive::from_reader(DATA.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(eager.names(), streamed.iter().map(|(n, _)| n.as_str()).collect::<Vec<_>>());
+    for (name, body) in &streamed {
+        assert_eq!(eager.get(name), Some(body.as_str()));
+    }
+}
+
+#[test]
+fn no_boundary_over_reader() {
+    let mut entries = Archive::from_reader("no boundary here".as_bytes());
+    match entries.next() {
+        Some(Err(Error::NoBoundary)) => {}
+        other => panic!("expected NoBoundary error, got {other:?}"),
+    }
+}
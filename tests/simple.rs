@@ -20,7 +20,7 @@ static OTHER_DATA: &str = "<=====> hello.md\
 #[test]
 fn get_names() {
     let archive = Archive::parse(DATA).unwrap();
-    assert_eq!(archive.names(), ["foo.txt", "hello.md"])
+    assert_eq!(archive.names(), ["hello.md", "foo.txt"])
 }
 
 #[test]
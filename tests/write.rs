@@ -0,0 +1,57 @@
+#![cfg(feature = "write")]
+use hrx_get::Archive;
+
+static DATA: &str = "<===> hello.md\
+                     \n# Hello world\
+                     \nThis is a simple markdown file.\
+                     \n\
+                     \n<===>\
+                     \nThis is just a comment.\
+                     \n<===> foo.txt\
+                     \nThis is something else.\n";
+
+#[test]
+fn round_trip() {
+    let archive = Archive::parse(DATA).unwrap();
+    let serialized = archive.serialize().unwrap();
+    let reparsed = Archive::parse(&serialized).unwrap();
+    assert_eq!(archive.names(), reparsed.names());
+    for name in archive.names() {
+        assert_eq!(archive.get(name), reparsed.get(name));
+    }
+}
+
+#[test]
+fn new_insert_remove() {
+    let mut archive = Archive::new();
+    assert_eq!(archive.names(), Vec::<&str>::new());
+    archive.insert("foo.txt", "hello\n");
+    assert_eq!(archive.get("foo.txt"), Some("hello\n"));
+    assert_eq!(archive.remove("foo.txt"), Some("hello\n".to_string()));
+    assert_eq!(archive.get("foo.txt"), None);
+}
+
+#[test]
+fn serialize_empty() {
+    let archive = Archive::new();
+    assert_eq!(archive.serialize().unwrap(), "<=>\n");
+}
+
+#[test]
+fn unsafe_boundary_grows() {
+    let mut archive = Archive::new();
+    archive.insert("a.txt", "line\n<=>\nmore");
+    let serialized = archive.serialize().unwrap();
+    let reparsed = Archive::parse(&serialized).unwrap();
+    assert_eq!(reparsed.get("a.txt"), Some("line\n<=>\nmore"));
+}
+
+#[test]
+fn fixed_boundary_reports_offenders() {
+    let mut archive = Archive::new();
+    archive.insert("a.txt", "line\n<=>\nmore");
+    match archive.serialize_with_boundary_len(1) {
+        Err(hrx_get::Error::UnsafeBoundary(names)) => assert_eq!(names, vec!["a.txt".to_string()]),
+        other => panic!("expected UnsafeBoundary error, got {other:?}"),
+    }
+}